@@ -18,12 +18,17 @@
 //! ```
 
 use async_trait::async_trait;
+use futures::{Stream, StreamExt};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::VecDeque;
+use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
-use tracing::{debug, error, info};
+use tokio::time::sleep;
+use tracing::{debug, error, info, warn};
 
 /// Errors that can occur when communicating with the REST backend
 #[derive(Error, Debug)]
@@ -43,11 +48,145 @@ pub enum ClientError {
     /// Tool not found
     #[error("Tool not found: {0}")]
     ToolNotFound(String),
+
+    /// Backend returned a non-success HTTP status
+    #[error("HTTP {status}: {body}")]
+    UnexpectedStatus {
+        status: reqwest::StatusCode,
+        body: String,
+    },
+
+    /// Request timed out
+    #[error("Timeout: {0}")]
+    Timeout(String),
+}
+
+impl ClientError {
+    /// Fold reqwest timeout errors into the distinct [`ClientError::Timeout`]
+    /// variant so callers can match on timeouts without inspecting reqwest
+    fn normalize_timeout(self) -> Self {
+        match self {
+            Self::HttpError(e) if e.is_timeout() => Self::Timeout(e.to_string()),
+            other => other,
+        }
+    }
 }
 
 /// Result type for client operations
 pub type Result<T> = std::result::Result<T, ClientError>;
 
+/// Retry behavior for requests to the REST backend.
+///
+/// By default only idempotent requests (tool listing, health checks) are
+/// retried on transient failures; tool execution is retried solely when the
+/// request provably never reached the backend (connect errors), since a tool
+/// call may have side effects. Set `retry_non_idempotent` to retry tool
+/// execution on the listed statuses as well.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts (1 = no retries)
+    pub max_attempts: u32,
+    /// Backoff before the first retry; doubles each attempt
+    pub initial_backoff: Duration,
+    /// Upper bound for the exponential backoff
+    pub max_backoff: Duration,
+    /// HTTP statuses considered transient and worth retrying
+    pub retry_on_status: Vec<u16>,
+    /// Retry tool execution on transient statuses even though the backend
+    /// may already have run the tool (only safe for idempotent tools)
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            retry_on_status: vec![429, 502, 503, 504],
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that disables retries entirely
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Whether `error` is worth retrying for a request with the given
+    /// idempotency. Connect errors are always retryable (the request never
+    /// reached the backend); everything else requires the request to be
+    /// idempotent or `retry_non_idempotent` to be set.
+    fn should_retry(&self, error: &ClientError, idempotent: bool) -> bool {
+        match error {
+            ClientError::HttpError(e) if e.is_connect() => true,
+            ClientError::HttpError(e) => {
+                (idempotent || self.retry_non_idempotent) && (e.is_timeout() || e.is_request())
+            },
+            ClientError::UnexpectedStatus { status, .. } => {
+                (idempotent || self.retry_non_idempotent)
+                    && self.retry_on_status.contains(&status.as_u16())
+            },
+            ClientError::Timeout(_) => idempotent || self.retry_non_idempotent,
+            _ => false,
+        }
+    }
+}
+
+/// Default connection-establishment timeout in seconds
+const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Default per-attempt request timeout in seconds
+const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Per-request timeout substituted for streaming calls. The client-level
+/// timeout runs until the response body is fully read, which would cut off
+/// long-running tool streams, so streaming requests replace it with this
+/// effectively-unbounded cap and bound only the initial response instead.
+const STREAMING_BODY_TIMEOUT_SECS: u64 = 24 * 60 * 60;
+
+/// Timeout settings for backend requests.
+///
+/// `connect` and `request` are enforced by the underlying HTTP client per
+/// attempt; `total` bounds an entire operation including retries, so a hung
+/// backend cannot stall a tool call indefinitely.
+#[derive(Debug, Clone)]
+pub struct TimeoutConfig {
+    /// TCP connection establishment timeout
+    pub connect: Option<Duration>,
+    /// Timeout for each individual request attempt
+    pub request: Option<Duration>,
+    /// Wall-clock budget for an operation across all retry attempts
+    pub total: Option<Duration>,
+}
+
+impl Default for TimeoutConfig {
+    fn default() -> Self {
+        Self {
+            connect: Some(Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS)),
+            request: Some(Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS)),
+            total: None,
+        }
+    }
+}
+
+impl TimeoutConfig {
+    /// Configuration with no timeouts at all (used when the caller supplies
+    /// a custom reqwest client that carries its own timeout settings)
+    pub fn none() -> Self {
+        Self {
+            connect: None,
+            request: None,
+            total: None,
+        }
+    }
+}
+
 /// Tool information from the backend
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolInfo {
@@ -71,32 +210,152 @@ pub struct ToolExecutionResult {
     pub error: Option<String>,
 }
 
+/// Callback that produces a fresh bearer token for each request
+pub type TokenProvider = Arc<dyn Fn() -> String + Send + Sync>;
+
+/// Authentication applied to every backend request.
+///
+/// Configured per client via [`RestToolClient::with_auth`]; the default is
+/// [`AuthConfig::None`]. Use [`AuthConfig::TokenProvider`] when tokens expire
+/// and must be refreshed by the host (e.g. OAuth behind a gateway).
+#[derive(Clone, Default)]
+pub enum AuthConfig {
+    /// No authentication (default)
+    #[default]
+    None,
+    /// Static `Authorization: Bearer <token>` header
+    Bearer(String),
+    /// API key sent in a custom header (e.g. `X-Api-Key`)
+    ApiKey { header: String, key: String },
+    /// HTTP basic authentication
+    Basic {
+        username: String,
+        password: Option<String>,
+    },
+    /// Bearer token fetched from a callback before each request
+    TokenProvider(TokenProvider),
+}
+
+impl std::fmt::Debug for AuthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print credential material
+        match self {
+            Self::None => write!(f, "None"),
+            Self::Bearer(_) => write!(f, "Bearer(***)"),
+            Self::ApiKey { header, .. } => write!(f, "ApiKey {{ header: {header:?}, key: *** }}"),
+            Self::Basic { username, .. } => {
+                write!(f, "Basic {{ username: {username:?}, password: *** }}")
+            },
+            Self::TokenProvider(_) => write!(f, "TokenProvider(..)"),
+        }
+    }
+}
+
 /// REST client for communicating with MCP tool backends
 #[derive(Clone)]
 pub struct RestToolClient {
     base_url: String,
     client: Client,
+    retry: RetryPolicy,
+    auth: AuthConfig,
+    timeouts: TimeoutConfig,
+    /// Whether `client` was supplied by the caller via [`Self::with_client`]
+    /// (and must not be rebuilt by [`Self::with_timeouts`])
+    custom_client: bool,
 }
 
 impl RestToolClient {
-    /// Create a new REST tool client
+    /// Create a new REST tool client with default timeouts
     pub fn new(base_url: impl Into<String>) -> Self {
         let base = base_url.into();
         // Remove trailing slash if present
         let base = base.trim_end_matches('/').to_string();
+        let timeouts = TimeoutConfig::default();
         Self {
             base_url: base,
-            client: Client::new(),
+            client: Self::build_http_client(&timeouts),
+            retry: RetryPolicy::default(),
+            auth: AuthConfig::None,
+            timeouts,
+            custom_client: false,
         }
     }
 
-    /// Create a new client with a custom reqwest client
+    /// Create a new client with a custom reqwest client.
+    ///
+    /// Connect and per-request timeouts are left to the supplied client;
+    /// only a `total` budget set via [`with_timeouts`](Self::with_timeouts)
+    /// applies on top.
     pub fn with_client(base_url: impl Into<String>, client: Client) -> Self {
         let base = base_url.into();
         let base = base.trim_end_matches('/').to_string();
         Self {
             base_url: base,
             client,
+            retry: RetryPolicy::default(),
+            auth: AuthConfig::None,
+            timeouts: TimeoutConfig::none(),
+            custom_client: true,
+        }
+    }
+
+    /// Build an HTTP client honoring the connect/request timeouts
+    fn build_http_client(timeouts: &TimeoutConfig) -> Client {
+        let mut builder = Client::builder();
+        if let Some(connect) = timeouts.connect {
+            builder = builder.connect_timeout(connect);
+        }
+        if let Some(request) = timeouts.request {
+            builder = builder.timeout(request);
+        }
+        builder.build().expect("failed to build HTTP client")
+    }
+
+    /// Set the timeout configuration for backend requests.
+    ///
+    /// For clients built by [`new`](Self::new) this rebuilds the underlying
+    /// HTTP client to apply `connect` and `request`. A custom client supplied
+    /// via [`with_client`](Self::with_client) is never replaced: only the
+    /// `total` budget (and the streaming first-response bound) applies, and a
+    /// warning is logged if `connect` or `request` is set so the caller knows
+    /// to configure those on their own client.
+    pub fn with_timeouts(mut self, timeouts: TimeoutConfig) -> Self {
+        if self.custom_client {
+            if timeouts.connect.is_some() || timeouts.request.is_some() {
+                warn!(
+                    "connect/request timeouts do not reconfigure a custom HTTP client; \
+                     set them on the client passed to with_client"
+                );
+            }
+        } else if timeouts.connect.is_some() || timeouts.request.is_some() {
+            self.client = Self::build_http_client(&timeouts);
+        }
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Set the retry policy for backend requests
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Set the authentication applied to backend requests
+    pub fn with_auth(mut self, auth: AuthConfig) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Apply the configured authentication to a request
+    fn authed(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match &self.auth {
+            AuthConfig::None => request,
+            AuthConfig::Bearer(token) => request.bearer_auth(token),
+            AuthConfig::ApiKey { header, key } => request.header(header, key),
+            AuthConfig::Basic { username, password } => {
+                request.basic_auth(username, password.as_deref())
+            },
+            AuthConfig::TokenProvider(provider) => request.bearer_auth(provider()),
         }
     }
 
@@ -105,29 +364,87 @@ impl RestToolClient {
         &self.base_url
     }
 
+    /// Run `op` with retries per the client's [`RetryPolicy`], bounded by
+    /// the `total` timeout budget when one is configured.
+    async fn with_retries<T, F, Fut>(&self, what: &str, idempotent: bool, op: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        match self.timeouts.total {
+            Some(total) => {
+                match tokio::time::timeout(total, self.retry_attempts(what, idempotent, op)).await {
+                    Ok(result) => result,
+                    Err(_) => Err(ClientError::Timeout(format!(
+                        "{} exceeded total timeout of {:?}",
+                        what, total
+                    ))),
+                }
+            },
+            None => self.retry_attempts(what, idempotent, op).await,
+        }
+    }
+
+    /// The retry loop itself, without the total budget
+    async fn retry_attempts<T, F, Fut>(&self, what: &str, idempotent: bool, mut op: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        let mut backoff = self.retry.initial_backoff;
+        let max_attempts = self.retry.max_attempts.max(1);
+
+        for attempt in 1..=max_attempts {
+            match op().await {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt < max_attempts && self.retry.should_retry(&e, idempotent) => {
+                    warn!(
+                        "{} failed (attempt {}): {}, retrying in {:?}",
+                        what, attempt, e, backoff
+                    );
+                    sleep(backoff).await;
+                    backoff = (backoff * 2).min(self.retry.max_backoff);
+                },
+                Err(e) => return Err(e.normalize_timeout()),
+            }
+        }
+
+        unreachable!("retry loop always returns on the final attempt")
+    }
+
     /// Check if the backend is healthy
     pub async fn health_check(&self) -> Result<bool> {
         let url = format!("{}/health", self.base_url);
         debug!("Health check: {}", url);
 
-        let response = self.client.get(&url).send().await?;
+        let response = self
+            .authed(self.client.get(&url))
+            .send()
+            .await
+            .map_err(|e| ClientError::from(e).normalize_timeout())?;
 
         Ok(response.status().is_success())
     }
 
-    /// List available tools from the backend
+    /// List available tools from the backend, retrying transient failures
     pub async fn list_tools(&self) -> Result<Vec<ToolInfo>> {
+        self.with_retries("list_tools", true, || self.list_tools_once())
+            .await
+    }
+
+    /// Single attempt at listing tools
+    async fn list_tools_once(&self) -> Result<Vec<ToolInfo>> {
         // Try REST endpoint first, fall back to MCP endpoint
         let rest_url = format!("{}/tools", self.base_url);
         let mcp_url = format!("{}/mcp/tools", self.base_url);
 
         debug!("Listing tools from: {}", rest_url);
 
-        let response = match self.client.get(&rest_url).send().await {
+        let response = match self.authed(self.client.get(&rest_url)).send().await {
             Ok(r) if r.status().is_success() => r,
             _ => {
                 debug!("REST endpoint failed, trying MCP endpoint: {}", mcp_url);
-                self.client.get(&mcp_url).send().await?
+                self.authed(self.client.get(&mcp_url)).send().await?
             },
         };
 
@@ -135,10 +452,7 @@ impl RestToolClient {
             let status = response.status();
             let text = response.text().await.unwrap_or_default();
             error!("Failed to list tools: {} - {}", status, text);
-            return Err(ClientError::BackendError(format!(
-                "HTTP {}: {}",
-                status, text
-            )));
+            return Err(ClientError::UnexpectedStatus { status, body: text });
         }
 
         let body: Value = response.json().await?;
@@ -150,8 +464,19 @@ impl RestToolClient {
         Ok(tools)
     }
 
-    /// Execute a tool on the backend
+    /// Execute a tool on the backend.
+    ///
+    /// Retries follow the client's [`RetryPolicy`]: by default only connect
+    /// errors are retried, since the backend may already have run the tool.
     pub async fn execute_tool(&self, name: &str, arguments: Value) -> Result<ToolExecutionResult> {
+        self.with_retries("execute_tool", false, || {
+            self.execute_tool_once(name, arguments.clone())
+        })
+        .await
+    }
+
+    /// Single attempt at executing a tool
+    async fn execute_tool_once(&self, name: &str, arguments: Value) -> Result<ToolExecutionResult> {
         // Try REST endpoint first, fall back to MCP endpoint
         let rest_url = format!("{}/tools/{}/call", self.base_url, name);
         let mcp_url = format!("{}/mcp/execute", self.base_url);
@@ -160,7 +485,12 @@ impl RestToolClient {
 
         // Try REST-style endpoint first
         let rest_body = serde_json::json!({ "arguments": arguments });
-        let response = match self.client.post(&rest_url).json(&rest_body).send().await {
+        let response = match self
+            .authed(self.client.post(&rest_url))
+            .json(&rest_body)
+            .send()
+            .await
+        {
             Ok(r) if r.status().is_success() || r.status() == reqwest::StatusCode::NOT_FOUND => r,
             _ => {
                 // Fall back to MCP-style endpoint
@@ -169,7 +499,10 @@ impl RestToolClient {
                     "tool": name,
                     "arguments": arguments
                 });
-                self.client.post(&mcp_url).json(&mcp_body).send().await?
+                self.authed(self.client.post(&mcp_url))
+                    .json(&mcp_body)
+                    .send()
+                    .await?
             },
         };
 
@@ -191,15 +524,156 @@ impl RestToolClient {
             let status = response.status();
             let text = response.text().await.unwrap_or_default();
             error!("Failed to execute tool {}: {} - {}", name, status, text);
-            return Err(ClientError::BackendError(format!(
-                "HTTP {}: {}",
-                status, text
-            )));
+            return Err(ClientError::UnexpectedStatus { status, body: text });
         }
 
         let result: ToolExecutionResult = response.json().await?;
         Ok(result)
     }
+
+    /// Execute a tool on the backend, streaming incremental output.
+    ///
+    /// Posts to the tool's call endpoint with `"stream": true` and an SSE
+    /// accept header, then decodes the response body incrementally: both
+    /// `text/event-stream` (`data:` events) and newline-delimited JSON bodies
+    /// yield one [`ProxyContent`] per event. Streaming requests are not
+    /// retried since partial output may already have been consumed, and the
+    /// per-attempt `request` timeout bounds only the initial response (the
+    /// client-level timeout would otherwise kill streams longer than it).
+    pub async fn execute_tool_streaming(
+        &self,
+        name: &str,
+        arguments: Value,
+    ) -> Result<ContentStream> {
+        let url = format!("{}/tools/{}/call", self.base_url, name);
+        debug!("Executing tool {} on backend (streaming)", name);
+
+        let body = serde_json::json!({ "arguments": arguments, "stream": true });
+        let send = self
+            .authed(self.client.post(&url))
+            .header(
+                reqwest::header::ACCEPT,
+                "text/event-stream, application/x-ndjson",
+            )
+            // Override the client-level timeout, which runs until the body
+            // finishes and would cut long streams off mid-flight
+            .timeout(Duration::from_secs(STREAMING_BODY_TIMEOUT_SECS))
+            .json(&body)
+            .send();
+
+        let response =
+            match self.timeouts.request {
+                Some(request_timeout) => tokio::time::timeout(request_timeout, send)
+                    .await
+                    .map_err(|_| {
+                        ClientError::Timeout(format!(
+                            "execute_tool_streaming got no response within {:?}",
+                            request_timeout
+                        ))
+                    })?,
+                None => send.await,
+            }
+            .map_err(|e| ClientError::from(e).normalize_timeout())?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(ClientError::ToolNotFound(name.to_string()));
+        }
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            error!("Failed to stream tool {}: {} - {}", name, status, text);
+            return Err(ClientError::UnexpectedStatus { status, body: text });
+        }
+
+        let chunks = response
+            .bytes_stream()
+            .map(|chunk| chunk.map(|bytes| bytes.to_vec()));
+        Ok(Box::pin(decode_content_stream(chunks)))
+    }
+}
+
+/// Stream of incremental tool output from [`RestToolClient::execute_tool_streaming`]
+pub type ContentStream = Pin<Box<dyn Stream<Item = Result<ProxyContent>> + Send>>;
+
+/// Decode a streaming response body (SSE or newline-delimited JSON) into
+/// [`ProxyContent`] items, handling payloads split across chunk boundaries.
+fn decode_content_stream<S>(chunks: S) -> impl Stream<Item = Result<ProxyContent>> + Send
+where
+    S: Stream<Item = reqwest::Result<Vec<u8>>> + Unpin + Send + 'static,
+{
+    // Buffer raw bytes and only decode complete lines: decoding per network
+    // chunk would corrupt multi-byte UTF-8 characters split across chunks.
+    let state = (chunks, Vec::new(), VecDeque::new(), false);
+    futures::stream::unfold(
+        state,
+        |(mut chunks, mut buffer, mut pending, mut done)| async move {
+            loop {
+                if let Some(item) = pending.pop_front() {
+                    return Some((item, (chunks, buffer, pending, done)));
+                }
+                if done {
+                    return None;
+                }
+
+                match chunks.next().await {
+                    Some(Ok(bytes)) => {
+                        buffer.extend_from_slice(&bytes);
+                        while let Some(pos) = buffer.iter().position(|&b| b == b'\n') {
+                            let line: Vec<u8> = buffer.drain(..=pos).collect();
+                            if let Some(content) =
+                                parse_stream_line(&String::from_utf8_lossy(&line))
+                            {
+                                pending.push_back(Ok(content));
+                            }
+                        }
+                    },
+                    Some(Err(e)) => {
+                        done = true;
+                        pending.push_back(Err(ClientError::HttpError(e)));
+                    },
+                    None => {
+                        done = true;
+                        let rest = std::mem::take(&mut buffer);
+                        if let Some(content) = parse_stream_line(&String::from_utf8_lossy(&rest)) {
+                            pending.push_back(Ok(content));
+                        }
+                    },
+                }
+            }
+        },
+    )
+}
+
+/// Parse one line of a streaming body.
+///
+/// SSE control fields, comments, blank lines, and the `[DONE]` sentinel are
+/// skipped; payloads that do not parse as [`ProxyContent`] pass through as
+/// plain text.
+fn parse_stream_line(line: &str) -> Option<ProxyContent> {
+    let line = line.trim_end_matches(['\n', '\r']);
+    if line.is_empty() || line.starts_with(':') {
+        return None;
+    }
+
+    let data = if let Some(rest) = line.strip_prefix("data:") {
+        rest.trim_start()
+    } else if matches!(line.split_once(':'), Some(("event" | "id" | "retry", _))) {
+        return None;
+    } else {
+        line
+    };
+
+    if data.is_empty() || data == "[DONE]" {
+        return None;
+    }
+
+    match serde_json::from_str::<ProxyContent>(data) {
+        Ok(content) => Some(content),
+        Err(_) => Some(ProxyContent::Text {
+            text: data.to_string(),
+        }),
+    }
 }
 
 // ============================================================================
@@ -365,6 +839,195 @@ mod tests {
         assert_eq!(client.base_url(), "http://localhost:8080");
     }
 
+    #[test]
+    fn test_retry_policy_defaults() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.max_attempts, 3);
+        assert!(policy.retry_on_status.contains(&502));
+        assert!(!policy.retry_non_idempotent);
+    }
+
+    #[test]
+    fn test_retry_policy_status_classification() {
+        let policy = RetryPolicy::default();
+        let transient = ClientError::UnexpectedStatus {
+            status: reqwest::StatusCode::BAD_GATEWAY,
+            body: String::new(),
+        };
+        let permanent = ClientError::UnexpectedStatus {
+            status: reqwest::StatusCode::BAD_REQUEST,
+            body: String::new(),
+        };
+
+        // Idempotent requests retry transient statuses, tool execution does not
+        assert!(policy.should_retry(&transient, true));
+        assert!(!policy.should_retry(&transient, false));
+        assert!(!policy.should_retry(&permanent, true));
+
+        let mut policy = policy;
+        policy.retry_non_idempotent = true;
+        assert!(policy.should_retry(&transient, false));
+    }
+
+    #[test]
+    fn test_auth_headers_applied() {
+        let bearer = RestToolClient::new("http://localhost:8080")
+            .with_auth(AuthConfig::Bearer("secret".to_string()));
+        let request = bearer
+            .authed(bearer.client.get("http://localhost:8080/tools"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.headers().get("authorization").unwrap(),
+            "Bearer secret"
+        );
+
+        let api_key = RestToolClient::new("http://localhost:8080").with_auth(AuthConfig::ApiKey {
+            header: "X-Api-Key".to_string(),
+            key: "k123".to_string(),
+        });
+        let request = api_key
+            .authed(api_key.client.get("http://localhost:8080/tools"))
+            .build()
+            .unwrap();
+        assert_eq!(request.headers().get("x-api-key").unwrap(), "k123");
+
+        let provider = RestToolClient::new("http://localhost:8080")
+            .with_auth(AuthConfig::TokenProvider(Arc::new(|| "fresh".to_string())));
+        let request = provider
+            .authed(provider.client.get("http://localhost:8080/tools"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            request.headers().get("authorization").unwrap(),
+            "Bearer fresh"
+        );
+    }
+
+    #[test]
+    fn test_parse_stream_line() {
+        // SSE data event with ProxyContent JSON
+        let content = parse_stream_line("data: {\"type\":\"text\",\"text\":\"hi\"}\n").unwrap();
+        assert!(matches!(content, ProxyContent::Text { text } if text == "hi"));
+
+        // Plain NDJSON line that is not ProxyContent passes through as text
+        let content = parse_stream_line("partial output\n").unwrap();
+        assert!(matches!(content, ProxyContent::Text { text } if text == "partial output"));
+
+        // Control fields, comments, blanks, and the sentinel are skipped
+        assert!(parse_stream_line("event: progress\n").is_none());
+        assert!(parse_stream_line(": keep-alive\n").is_none());
+        assert!(parse_stream_line("\r\n").is_none());
+        assert!(parse_stream_line("data: [DONE]\n").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_decode_content_stream_split_utf8() {
+        // A multi-byte UTF-8 character split across a chunk boundary must
+        // survive reassembly without replacement characters
+        let payload = "data: {\"type\":\"text\",\"text\":\"h\u{e9}llo\"}\n".as_bytes();
+        let split = payload.iter().position(|&b| b == 0xC3).unwrap() + 1;
+        let chunks: Vec<reqwest::Result<Vec<u8>>> =
+            vec![Ok(payload[..split].to_vec()), Ok(payload[split..].to_vec())];
+
+        let items: Vec<_> = decode_content_stream(futures::stream::iter(chunks))
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 1);
+        assert!(
+            matches!(items[0].as_ref().unwrap(), ProxyContent::Text { text } if text == "h\u{e9}llo")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_decode_content_stream_across_chunks() {
+        // One SSE event split across two chunks, followed by the sentinel
+        let chunks: Vec<reqwest::Result<Vec<u8>>> = vec![
+            Ok(b"data: {\"type\":\"text\",\"text\":\"hel".to_vec()),
+            Ok(b"lo\"}\n\ndata: [DONE]\n".to_vec()),
+        ];
+        let items: Vec<_> = decode_content_stream(futures::stream::iter(chunks))
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 1);
+        assert!(
+            matches!(items[0].as_ref().unwrap(), ProxyContent::Text { text } if text == "hello")
+        );
+    }
+
+    #[test]
+    fn test_timeout_config_defaults() {
+        let timeouts = TimeoutConfig::default();
+        assert_eq!(timeouts.connect, Some(Duration::from_secs(10)));
+        assert_eq!(timeouts.request, Some(Duration::from_secs(30)));
+        assert_eq!(timeouts.total, None);
+
+        let none = TimeoutConfig::none();
+        assert_eq!(none.connect, None);
+        assert_eq!(none.request, None);
+        assert_eq!(none.total, None);
+    }
+
+    #[tokio::test]
+    async fn test_total_timeout_bounds_retries() {
+        let client = RestToolClient::new("http://localhost:8080").with_timeouts(TimeoutConfig {
+            total: Some(Duration::from_millis(20)),
+            ..TimeoutConfig::none()
+        });
+
+        let result: Result<()> = client
+            .with_retries("hang", true, || async {
+                sleep(Duration::from_secs(5)).await;
+                Ok(())
+            })
+            .await;
+
+        assert!(matches!(result, Err(ClientError::Timeout(_))));
+    }
+
+    #[test]
+    fn test_with_timeouts_keeps_custom_client() {
+        // A caller-supplied client must survive with_timeouts; only the
+        // timeout config itself is stored (connect/request warn, total applies)
+        let client = RestToolClient::with_client("http://localhost:8080", Client::new())
+            .with_timeouts(TimeoutConfig::default());
+
+        assert!(client.custom_client);
+        assert_eq!(client.timeouts.connect, Some(Duration::from_secs(10)));
+        assert_eq!(client.timeouts.request, Some(Duration::from_secs(30)));
+    }
+
+    #[test]
+    fn test_timeout_is_retryable_when_idempotent() {
+        let policy = RetryPolicy::default();
+        let timeout = ClientError::Timeout("request timed out".to_string());
+        assert!(policy.should_retry(&timeout, true));
+        assert!(!policy.should_retry(&timeout, false));
+    }
+
+    #[test]
+    fn test_auth_debug_redacts_credentials() {
+        let debug = format!("{:?}", AuthConfig::Bearer("secret".to_string()));
+        assert!(!debug.contains("secret"));
+
+        let debug = format!(
+            "{:?}",
+            AuthConfig::Basic {
+                username: "user".to_string(),
+                password: Some("hunter2".to_string()),
+            }
+        );
+        assert!(!debug.contains("hunter2"));
+    }
+
+    #[test]
+    fn test_retry_policy_none() {
+        let policy = RetryPolicy::none();
+        assert_eq!(policy.max_attempts, 1);
+    }
+
     #[test]
     fn test_proxy_tool_creation() {
         let info = ToolInfo {